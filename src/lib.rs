@@ -10,6 +10,20 @@
 
 use core::{mem::MaybeUninit, ptr};
 
+/// Error returned by `ConstVec::from_slice` when the source slice
+/// holds more elements than the ConstVec's capacity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError;
+
+/// Builds a `ConstVec` at compile time from a literal list of
+/// elements, mirroring `vec!`'s ergonomics.
+#[macro_export]
+macro_rules! const_vec {
+    ($t:ty; $n:expr => $($elem:expr),* $(,)?) => {
+        $crate::ConstVec::<$t, $n>::from_array([$($elem),*])
+    };
+}
+
 /// A `ConstVec` is an array with a Vec like API,
 /// but usable in constant functions.
 ///
@@ -25,16 +39,75 @@ pub struct ConstVec<T, const N: usize> {
 }
 
 impl<T, const N: usize> ConstVec<T, { N }> {
-    const unsafe fn as_slice_mut(&mut self) -> &mut [T] {
+    /// Returns the initialized elements of the ConstVec as a slice.
+    pub const fn as_slice(&self) -> &[T] {
+        let len = self.len();
+        let ptr = &self.data as *const _ as *const T;
+        unsafe { &*ptr::slice_from_raw_parts(ptr, len) }
+    }
+
+    /// Returns the initialized elements of the ConstVec as a
+    /// mutable slice.
+    pub const fn as_slice_mut(&mut self) -> &mut [T] {
         let len = self.len();
         let ptr = &mut self.data as *mut _ as *mut T;
-        &mut *ptr::slice_from_raw_parts_mut(ptr, len)
+        unsafe { &mut *ptr::slice_from_raw_parts_mut(ptr, len) }
     }
 
     const unsafe fn as_uninit_slice_mut(&mut self) -> &mut [MaybeUninit<T>] {
         let ptr = &mut self.data as *mut _ as *mut MaybeUninit<T>;
         &mut *ptr::slice_from_raw_parts_mut(ptr, N)
     }
+
+    /// Returns a reference to the element at `index`.
+    ///
+    /// `index` is checked against the length with a `debug_assert!`;
+    /// the slice indexing used here still bounds checks it as well,
+    /// and panics if `index` is out of bounds.
+    pub const unsafe fn get_unchecked(&self, index: usize) -> &T {
+        debug_assert!(index < self.len());
+        &self.as_slice()[index]
+    }
+
+    /// Returns a reference to the element at `index`, or `None`
+    /// if `index` is out of bounds.
+    pub const fn get(&self, index: usize) -> Option<&T> {
+        if index < self.len() {
+            Some(unsafe { self.get_unchecked(index) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the first element, or `None` if
+    /// the ConstVec is empty.
+    pub const fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the last element, or `None` if
+    /// the ConstVec is empty.
+    pub const fn last(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.get(self.len() - 1)
+        }
+    }
+
+    /// Returns a reference to the element at `index`.
+    ///
+    /// This exists because `core::ops::Index` can't be evaluated
+    /// in const context.
+    ///
+    /// # Panic
+    /// Panic's if `index` is out of bounds.
+    pub const fn index(&self, index: usize) -> &T {
+        match self.get(index) {
+            Some(data) => data,
+            None => panic!("ConstVec::index called with an out of bounds index!"),
+        }
+    }
 }
 
 impl<T, const N: usize> ConstVec<T, { N }> {
@@ -87,6 +160,26 @@ impl<T, const N: usize> ConstVec<T, { N }> {
     pub const unsafe fn set_len(&mut self, new_len: usize) {
         self.len = new_len;
     }
+
+    /// Borrows the ConstVec's backing storage as a `SliceVec`,
+    /// decoupling the element count from the const generic `N`.
+    ///
+    /// The returned `SliceVec` shares the ConstVec's own `len` field,
+    /// so pushes and pops made through it are immediately reflected
+    /// back in the original ConstVec.
+    pub const fn as_slice_vec(&mut self) -> SliceVec<'_, T> {
+        // Project `data` and `len` separately (instead of going
+        // through `as_uninit_slice_mut`, which borrows all of `self`)
+        // so the returned SliceVec can hold a live borrow of `len`
+        // alongside its borrow of the storage.
+        let data_ptr = &mut self.data as *mut _ as *mut MaybeUninit<T>;
+        let storage = unsafe { &mut *ptr::slice_from_raw_parts_mut(data_ptr, N) };
+
+        SliceVec {
+            storage,
+            len: SliceVecLen::Borrowed(&mut self.len),
+        }
+    }
 }
 
 /// Growing and shrinkin requires T: Copy,
@@ -135,11 +228,77 @@ impl<T: Copy, const N: usize> ConstVec<T, { N }> {
         }
     }
 
+    /// Builds a new ConstVec from `arr`, copying its elements into
+    /// the front of the backing storage.
+    ///
+    /// # Panic
+    /// Panic's if `M` is greater than the ConstVec's capacity.
+    pub const fn from_array<const M: usize>(arr: [T; M]) -> Self {
+        assert!(M <= N);
+
+        let mut this = Self::new();
+        let dst = unsafe { this.as_uninit_slice_mut() };
+
+        let mut i = 0;
+        while i < M {
+            dst[i] = MaybeUninit::new(arr[i]);
+            i += 1;
+        }
+
+        unsafe { this.set_len(M) };
+        this
+    }
+
+    /// Builds a new ConstVec by copying the elements of `src`.
+    /// Returns `CapacityError` if `src` doesn't fit.
+    pub const fn from_slice(src: &[T]) -> Result<Self, CapacityError> {
+        let len = src.len();
+        if len > N {
+            return Err(CapacityError);
+        }
+
+        let mut this = Self::new();
+        let dst = unsafe { this.as_uninit_slice_mut() };
+
+        let mut i = 0;
+        while i < len {
+            dst[i] = MaybeUninit::new(src[i]);
+            i += 1;
+        }
+
+        unsafe { this.set_len(len) };
+        Ok(this)
+    }
+
+    /// Bulk-copies as much of `src` as fits into the uninitialized
+    /// tail of the ConstVec. Returns the number of elements from
+    /// `src` that didn't fit.
+    pub const fn extend_from_slice(&mut self, src: &[T]) -> Result<(), usize> {
+        let len = self.len();
+        let room = N - len;
+        let to_copy = if src.len() < room { src.len() } else { room };
+
+        let dst = unsafe { self.as_uninit_slice_mut() };
+        let mut i = 0;
+        while i < to_copy {
+            dst[len + i] = MaybeUninit::new(src[i]);
+            i += 1;
+        }
+
+        unsafe { self.set_len(len + to_copy) };
+
+        if to_copy < src.len() {
+            Err(src.len() - to_copy)
+        } else {
+            Ok(())
+        }
+    }
+
     /// Pops the last element from the ConstVec.
     pub const unsafe fn pop_unchecked(&mut self) -> T {
         assert!(self.is_not_empty());
         let len = self.len() - 1;
-        let slice = unsafe { self.as_slice_mut() };
+        let slice = self.as_slice_mut();
         let popped = slice[len];
 
         self.set_len(len);
@@ -160,6 +319,325 @@ impl<T: Copy, const N: usize> ConstVec<T, { N }> {
     pub const fn clear(&mut self) {
         unsafe { self.set_len(0) }
     }
+
+    /// Inserts `data` at position `index`, shifting every element
+    /// after it one slot to the right.
+    pub const unsafe fn insert_unchecked(&mut self, index: usize, data: T) {
+        debug_assert!(index <= self.len());
+        debug_assert!(self.is_not_full());
+
+        let len = self.len();
+        let slice = self.as_uninit_slice_mut();
+
+        let mut j = len;
+        while j > index {
+            slice[j] = slice[j - 1];
+            j -= 1;
+        }
+
+        slice[index] = MaybeUninit::new(data);
+        self.set_len(len + 1);
+    }
+
+    /// Attempts to insert `data` at position `index`.
+    /// Returns a Result to indicate success or failure.
+    pub const fn try_insert(&mut self, index: usize, data: T) -> Result<(), T> {
+        if index > self.len() || self.is_full() {
+            Err(data)
+        } else {
+            unsafe {
+                self.insert_unchecked(index, data);
+                Ok(())
+            }
+        }
+    }
+
+    /// Inserts `data` at position `index`, shifting every element
+    /// after it one slot to the right.
+    ///
+    /// # Panic
+    /// Panic's if `index` is out of bounds, or the maximum capacity
+    /// was already reached.
+    pub const fn insert(&mut self, index: usize, data: T) {
+        match self.try_insert(index, data) {
+            Ok(_) => {}
+            Err(_) => panic!("ConstVec::insert called with an out of bounds index, or trough a ConstVec already at maximum capacity!"),
+        }
+    }
+
+    /// Removes the element at position `index`, shifting every
+    /// element after it one slot to the left.
+    pub const unsafe fn remove_unchecked(&mut self, index: usize) -> T {
+        debug_assert!(index < self.len());
+
+        let len = self.len();
+        let slice = self.as_slice_mut();
+        let removed = slice[index];
+
+        let mut j = index;
+        while j + 1 < len {
+            slice[j] = slice[j + 1];
+            j += 1;
+        }
+
+        self.set_len(len - 1);
+        removed
+    }
+
+    /// Attempts to remove the element at position `index`.
+    /// Returns `None` if `index` is out of bounds.
+    pub const fn try_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            None
+        } else {
+            Some(unsafe { self.remove_unchecked(index) })
+        }
+    }
+
+    /// Removes the element at position `index`, shifting every
+    /// element after it one slot to the left.
+    ///
+    /// # Panic
+    /// Panic's if `index` is out of bounds.
+    pub const fn remove(&mut self, index: usize) -> T {
+        match self.try_remove(index) {
+            Some(data) => data,
+            None => panic!("ConstVec::remove called with an out of bounds index!"),
+        }
+    }
+
+    /// Removes the element at position `index` by swapping it with
+    /// the last element, without preserving ordering.
+    pub const unsafe fn swap_remove_unchecked(&mut self, index: usize) -> T {
+        debug_assert!(index < self.len());
+
+        let len = self.len();
+        let slice = self.as_slice_mut();
+        let removed = slice[index];
+        slice[index] = slice[len - 1];
+
+        self.set_len(len - 1);
+        removed
+    }
+
+    /// Attempts to remove the element at position `index` by swapping
+    /// it with the last element, without preserving ordering.
+    /// Returns `None` if `index` is out of bounds.
+    pub const fn try_swap_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            None
+        } else {
+            Some(unsafe { self.swap_remove_unchecked(index) })
+        }
+    }
+
+    /// Removes the element at position `index` by swapping it with
+    /// the last element, without preserving ordering.
+    ///
+    /// # Panic
+    /// Panic's if `index` is out of bounds.
+    pub const fn swap_remove(&mut self, index: usize) -> T {
+        match self.try_swap_remove(index) {
+            Some(data) => data,
+            None => panic!("ConstVec::swap_remove called with an out of bounds index!"),
+        }
+    }
+
+    /// Pops the first `M` elements off the front of the ConstVec and
+    /// returns them as an array, shifting the remaining elements down
+    /// to index 0. Returns `None`, leaving the ConstVec untouched, if
+    /// fewer than `M` elements are present.
+    pub const fn take_array<const M: usize>(&mut self) -> Option<[T; M]> {
+        if self.len() < M {
+            return None;
+        }
+
+        let mut array = MaybeUninit::<[T; M]>::uninit();
+
+        unsafe {
+            let ptr = &mut array as *mut _ as *mut T;
+            let dst = &mut *ptr::slice_from_raw_parts_mut(ptr, M);
+            let src = self.as_slice_mut();
+
+            let mut i = 0;
+            while i < M {
+                dst[i] = src[i];
+                i += 1;
+            }
+        }
+
+        let len = self.len();
+        unsafe {
+            let slice = self.as_uninit_slice_mut();
+
+            let mut j = 0;
+            while j + M < len {
+                slice[j] = slice[j + M];
+                j += 1;
+            }
+
+            self.set_len(len - M);
+        }
+
+        Some(unsafe { *(&array as *const _ as *const [T; M]) })
+    }
+
+    /// Returns the elements currently held by the ConstVec, i.e.
+    /// whatever is left after any `take_array` calls so far. This is
+    /// an alias of `as_slice`, kept as its own name for call sites
+    /// that read as chunking out of a `ConstVec`.
+    pub const fn remaining(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+/// The length backing a `SliceVec`: either owned by the SliceVec
+/// itself, or borrowed from the `ConstVec` it was carved out of via
+/// `as_slice_vec`, so mutations made through the SliceVec stay
+/// visible in the original afterwards.
+enum SliceVecLen<'a> {
+    Owned(usize),
+    Borrowed(&'a mut usize),
+}
+
+impl<'a> SliceVecLen<'a> {
+    const fn get(&self) -> usize {
+        match self {
+            SliceVecLen::Owned(len) => *len,
+            SliceVecLen::Borrowed(len) => **len,
+        }
+    }
+
+    const fn set(&mut self, new_len: usize) {
+        match self {
+            SliceVecLen::Owned(len) => *len = new_len,
+            SliceVecLen::Borrowed(len) => **len = new_len,
+        }
+    }
+}
+
+/// A `SliceVec` is a `ConstVec` whose capacity lives in a borrowed
+/// slice instead of a const generic, so the same buffer can back
+/// vecs of different logical capacities at runtime.
+pub struct SliceVec<'a, T> {
+    storage: &'a mut [MaybeUninit<T>],
+    len: SliceVecLen<'a>,
+}
+
+impl<'a, T> SliceVec<'a, T> {
+    /// Wraps `storage` into a new, empty SliceVec.
+    pub const fn new(storage: &'a mut [MaybeUninit<T>]) -> Self {
+        Self {
+            storage,
+            len: SliceVecLen::Owned(0),
+        }
+    }
+
+    /// Returns the length of the SliceVec.
+    /// This is how many elements it currently contains.
+    pub const fn len(&self) -> usize {
+        self.len.get()
+    }
+
+    /// Returns the capacity of the SliceVec.
+    /// This is how many elements it can maximally hold.
+    pub const fn capacity(&self) -> usize {
+        self.storage.len()
+    }
+
+    /// Returns a bool to indicate whether the SliceVec
+    /// is empty or not.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a bool to indicate wheter the SliceVec
+    /// is not empty
+    pub const fn is_not_empty(&self) -> bool {
+        !self.is_empty()
+    }
+
+    /// Returns a bool to indicate whether the SliceVec
+    /// is full. This means the SliceVec has reached
+    /// its capacity, and does not have room for new
+    /// elements.
+    pub const fn is_full(&self) -> bool {
+        self.len() == self.capacity()
+    }
+
+    /// Returns bool to indicate whether the SliceVec
+    /// is not full.
+    pub const fn is_not_full(&self) -> bool {
+        self.len() < self.capacity()
+    }
+
+    pub const unsafe fn set_len(&mut self, new_len: usize) {
+        self.len.set(new_len);
+    }
+}
+
+/// Growing and shrinkin requires T: Copy,
+/// for the same reason as `ConstVec`: T: Copy implies
+/// T: !Drop, which keeps us from leaking in non-const
+/// functions since SliceVec can't implement Drop either.
+impl<'a, T: Copy> SliceVec<'a, T> {
+    /// Pushes `data` onto the SliceVec.
+    pub const unsafe fn push_unchecked(&mut self, data: T) {
+        debug_assert!(self.is_not_full());
+        let len = self.len();
+        self.storage[len] = MaybeUninit::new(data);
+
+        self.set_len(len + 1);
+    }
+
+    /// Attempts to push `data` onto the SliceVec.
+    /// Returns a Result to indicate success or failure.
+    pub const fn try_push(&mut self, data: T) -> Result<(), T> {
+        if self.is_full() {
+            Err(data)
+        } else {
+            unsafe {
+                self.push_unchecked(data);
+                Ok(())
+            }
+        }
+    }
+
+    /// Pushes `data` onto the SliceVec.
+    ///
+    /// # Panic
+    /// Panic's if the maximum capacity was already reached.
+    pub const fn push(&mut self, data: T) {
+        match self.try_push(data) {
+            Ok(_) => {}
+            Err(_) => panic!("SliceVec::push called trough a SliceVec already at maximum capacity!"),
+        }
+    }
+
+    /// Pops the last element from the SliceVec.
+    pub const unsafe fn pop_unchecked(&mut self) -> T {
+        assert!(self.is_not_empty());
+        let len = self.len() - 1;
+        let popped = *(&self.storage[len] as *const MaybeUninit<T> as *const T);
+
+        self.set_len(len);
+        popped
+    }
+
+    /// Pops the last element from the SliceVec and
+    /// returns it, or None if it is empty.
+    pub const fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(unsafe { self.pop_unchecked() })
+        }
+    }
+
+    /// Clears the SliceVec.
+    pub const fn clear(&mut self) {
+        unsafe { self.set_len(0) }
+    }
 }
 
 #[cfg(test)]
@@ -187,4 +665,235 @@ mod tests {
     fn it_works() {
         const _: () = run();
     }
+
+    const fn run_insert_remove() {
+        let mut b = ConstVec::<_, 4>::new();
+        b.push(1i32);
+        b.push(2i32);
+        b.push(3i32);
+
+        b.insert(1, 99);
+        assert!(b.len() == 4);
+
+        match b.remove(1) {
+            99 => {}
+            _ => panic!("Expected the inserted element back out!"),
+        }
+
+        match b.swap_remove(0) {
+            1 => {}
+            _ => panic!("swap_remove should have returned the first element!"),
+        }
+
+        assert!(b.len() == 2);
+    }
+
+    #[test]
+    fn insert_remove_works() {
+        const _: () = run_insert_remove();
+    }
+
+    const fn run_insert_remove_errors() {
+        let mut b = ConstVec::<_, 4>::new();
+        b.push(1i32);
+        b.push(2i32);
+
+        match b.try_remove(5) {
+            None => {}
+            Some(_) => panic!("index 5 is out of bounds for a 2-element vec!"),
+        }
+
+        match b.try_swap_remove(5) {
+            None => {}
+            Some(_) => panic!("index 5 is out of bounds for a 2-element vec!"),
+        }
+
+        match b.try_insert(5, 99) {
+            Err(99) => {}
+            _ => panic!("index 5 is out of bounds for insert on a 2-element vec!"),
+        }
+
+        b.push(3i32);
+        b.push(4i32);
+        assert!(b.is_full());
+
+        match b.try_insert(0, 99) {
+            Err(99) => {}
+            _ => panic!("insert should fail once the ConstVec is full!"),
+        }
+    }
+
+    #[test]
+    fn insert_remove_error_paths_work() {
+        const _: () = run_insert_remove_errors();
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_panics_when_full() {
+        let mut b = ConstVec::<_, 1>::new();
+        b.push(1i32);
+        b.insert(0, 2i32);
+    }
+
+    #[test]
+    #[should_panic]
+    fn remove_panics_out_of_bounds() {
+        let mut b = ConstVec::<_, 4>::new();
+        b.push(1i32);
+        b.remove(5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn swap_remove_panics_out_of_bounds() {
+        let mut b = ConstVec::<_, 4>::new();
+        b.push(1i32);
+        b.swap_remove(5);
+    }
+
+    const fn run_take_array() {
+        let mut b = ConstVec::<_, 5>::new();
+        b.push(1i32);
+        b.push(2i32);
+        b.push(3i32);
+        b.push(4i32);
+        b.push(5i32);
+
+        match b.take_array::<2>() {
+            Some([1, 2]) => {}
+            _ => panic!("Expected the first two elements!"),
+        }
+
+        let remaining = b.remaining();
+        assert!(remaining.len() == 3);
+        assert!(remaining[0] == 3);
+        assert!(remaining[2] == 5);
+
+        match b.take_array::<10>() {
+            Some(_) => panic!("There aren't 10 elements left!"),
+            None => {}
+        }
+    }
+
+    #[test]
+    fn take_array_works() {
+        const _: () = run_take_array();
+    }
+
+    const fn run_slice_vec() {
+        let mut buf = [MaybeUninit::uninit(); 4];
+        let mut v = SliceVec::new(&mut buf);
+
+        v.push(1i32);
+        v.push(2i32);
+        assert!(v.len() == 2);
+        assert!(v.capacity() == 4);
+
+        match v.pop() {
+            Some(2) => {}
+            _ => panic!("Expected the last pushed element back out!"),
+        }
+    }
+
+    #[test]
+    fn slice_vec_works() {
+        const _: () = run_slice_vec();
+    }
+
+    #[test]
+    fn as_slice_vec_works() {
+        let mut b = ConstVec::<_, 4>::new();
+        b.push(1i32);
+
+        let mut sv = b.as_slice_vec();
+        assert_eq!(sv.len(), 1);
+        assert_eq!(sv.capacity(), 4);
+
+        sv.push(2i32);
+        assert_eq!(sv.pop(), Some(2));
+    }
+
+    #[test]
+    fn as_slice_vec_shares_len_with_its_constvec() {
+        let mut b = ConstVec::<_, 4>::new();
+        b.push(1i32);
+
+        {
+            let mut sv = b.as_slice_vec();
+            sv.push(2i32);
+            sv.push(3i32);
+        }
+
+        assert_eq!(b.len(), 3);
+        assert_eq!(b.as_slice(), &[1, 2, 3]);
+    }
+
+    const fn run_from_array_and_extend() {
+        let mut b = ConstVec::<i32, 5>::from_array([1, 2, 3]);
+        assert!(b.len() == 3);
+
+        match b.extend_from_slice(&[4, 5, 6]) {
+            Err(1) => {}
+            _ => panic!("Only 2 of the 3 extra elements should have fit!"),
+        }
+
+        assert!(b.len() == 5);
+        assert!(b.is_full());
+    }
+
+    #[test]
+    fn from_array_and_extend_work() {
+        const _: () = run_from_array_and_extend();
+    }
+
+    #[test]
+    fn from_slice_works() {
+        assert!(ConstVec::<i32, 2>::from_slice(&[1, 2, 3]).is_err());
+
+        let b = ConstVec::<i32, 4>::from_slice(&[1, 2]).unwrap();
+        assert_eq!(b.len(), 2);
+    }
+
+    #[test]
+    fn const_vec_macro_works() {
+        let b = const_vec![i32; 4 => 1, 2, 3];
+        assert_eq!(b.len(), 3);
+        assert_eq!(b.capacity(), 4);
+    }
+
+    const fn run_read_access() {
+        let b = ConstVec::<i32, 4>::from_array([1, 2, 3]);
+
+        assert!(b.as_slice()[1] == 2);
+
+        match b.first() {
+            Some(1) => {}
+            _ => panic!("Expected the first element!"),
+        }
+
+        match b.last() {
+            Some(3) => {}
+            _ => panic!("Expected the last element!"),
+        }
+
+        match b.get(3) {
+            Some(_) => panic!("There is no fourth element!"),
+            None => {}
+        }
+
+        assert!(*b.index(2) == 3);
+    }
+
+    #[test]
+    fn read_access_works() {
+        const _: () = run_read_access();
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_panics_out_of_bounds() {
+        let b = ConstVec::<i32, 4>::from_array([1, 2]);
+        b.index(2);
+    }
 }